@@ -0,0 +1,91 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum TessError {
+    TesseractNotFoundError,
+    CommandExitStatusError(String, String),
+    CacheIoError(String),
+    /// Tesseract couldn't find the `.traineddata` file for the requested
+    /// language, e.g. because it isn't installed or `TESSDATA_PREFIX` points
+    /// somewhere else.
+    LanguageDataMissing {
+        lang: String,
+        tessdata_dir: String,
+        stderr: String,
+    },
+    /// The `--psm` value was rejected by tesseract as out of range.
+    InvalidPageSegMode {
+        stderr: String,
+    },
+    /// The `--oem` value was rejected by tesseract as out of range.
+    InvalidOcrEngineMode {
+        stderr: String,
+    },
+    /// The input image exceeded tesseract's maximum dimensions.
+    ImageTooLarge {
+        stderr: String,
+    },
+    /// Tesseract ran but produced no recognizable text (e.g. a blank page).
+    EmptyPage {
+        stderr: String,
+    },
+    /// Tesseract warned about the image's resolution, e.g. a missing or
+    /// implausible DPI tag that it had to substitute a default for.
+    DpiWarning {
+        stderr: String,
+    },
+    /// The installed tesseract is older than a caller-specified minimum
+    /// version (see `TesseractEnv::require_version`).
+    UnsupportedVersion {
+        installed: String,
+        required: String,
+    },
+}
+
+impl fmt::Display for TessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TessError::TesseractNotFoundError => {
+                write!(f, "Could not find the tesseract executable")
+            }
+            TessError::CommandExitStatusError(status, stderr) => {
+                write!(f, "Tesseract exited with status {}: {}", status, stderr)
+            }
+            TessError::CacheIoError(message) => write!(f, "OCR cache I/O error: {}", message),
+            TessError::LanguageDataMissing {
+                lang, tessdata_dir, ..
+            } => write!(
+                f,
+                "Missing tesseract language data for '{}' in '{}'",
+                lang, tessdata_dir
+            ),
+            TessError::InvalidPageSegMode { stderr } => {
+                write!(f, "Invalid page segmentation mode: {}", stderr)
+            }
+            TessError::InvalidOcrEngineMode { stderr } => {
+                write!(f, "Invalid OCR engine mode: {}", stderr)
+            }
+            TessError::ImageTooLarge { stderr } => {
+                write!(f, "Image too large for tesseract: {}", stderr)
+            }
+            TessError::EmptyPage { stderr } => {
+                write!(f, "Tesseract found no text on the page: {}", stderr)
+            }
+            TessError::DpiWarning { stderr } => {
+                write!(f, "Tesseract warned about image resolution: {}", stderr)
+            }
+            TessError::UnsupportedVersion {
+                installed,
+                required,
+            } => write!(
+                f,
+                "Tesseract {} is older than the required minimum version {}",
+                installed, required
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TessError {}
+
+pub type TessResult<T> = Result<T, TessError>;
@@ -0,0 +1,205 @@
+use super::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::error::{TessError, TessResult};
+
+// Default eviction bound, chosen to comfortably hold a few thousand OCR
+// results without growing unbounded on long-running batch jobs.
+const DEFAULT_MAX_SIZE_BYTES: u64 = 512 * 1024 * 1024;
+
+/// A content-addressed cache of tesseract stdout, keyed by a digest of the
+/// image bytes plus the fully normalized argument list used to produce them.
+///
+/// This mirrors the way compiler wrappers (e.g. sccache) decide whether to
+/// invoke the underlying tool: hash everything that can affect the output,
+/// then look the digest up before doing the expensive work. Entries are
+/// evicted least-recently-used first once the directory exceeds
+/// `max_size_bytes`; a `get` hit bumps an entry's recency the same as a
+/// `put` does.
+pub struct Cache {
+    dir: PathBuf,
+    max_size_bytes: u64,
+}
+
+impl Cache {
+    /// Creates a cache rooted at `dir`, creating it if it doesn't exist.
+    pub fn new(dir: impl Into<PathBuf>, max_size_bytes: u64) -> TessResult<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| TessError::CacheIoError(e.to_string()))?;
+
+        Ok(Self {
+            dir,
+            max_size_bytes,
+        })
+    }
+
+    /// Creates a cache under the OS cache directory (e.g. `~/.cache` on
+    /// Linux, `~/Library/Caches` on macOS), with a default eviction bound.
+    pub fn with_default_dir() -> TessResult<Self> {
+        let base = dirs::cache_dir().ok_or_else(|| {
+            TessError::CacheIoError("could not determine OS cache directory".into())
+        })?;
+
+        Self::new(base.join("rusty-tesseract"), DEFAULT_MAX_SIZE_BYTES)
+    }
+
+    /// Looks up a previously stored result for `image_bytes` run with `args`,
+    /// returning `None` on a cache miss.
+    pub fn get(&self, image_bytes: &[u8], args: &Args) -> TessResult<Option<String>> {
+        let path = self.entry_path(image_bytes, args);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                touch(&path);
+                Ok(Some(contents))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(TessError::CacheIoError(e.to_string())),
+        }
+    }
+
+    /// Persists `output` as the result for `image_bytes` run with `args`,
+    /// evicting the oldest entries if the cache has grown past its bound.
+    ///
+    /// Eviction is best-effort: a housekeeping hiccup there must not turn an
+    /// already-successful `output` into an error for the caller.
+    pub fn put(&self, image_bytes: &[u8], args: &Args, output: &str) -> TessResult<()> {
+        let path = self.entry_path(image_bytes, args);
+        fs::write(&path, output).map_err(|e| TessError::CacheIoError(e.to_string()))?;
+
+        self.evict_if_over_budget();
+        Ok(())
+    }
+
+    fn entry_path(&self, image_bytes: &[u8], args: &Args) -> PathBuf {
+        self.dir.join(digest(image_bytes, args))
+    }
+
+    fn evict_if_over_budget(&self) {
+        let Ok(read_dir) = fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        let mut entries: Vec<_> = read_dir
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                let modified = metadata.modified().ok()?;
+                Some((entry.path(), metadata.len(), modified))
+            })
+            .collect();
+
+        let mut total_size: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if total_size <= self.max_size_bytes {
+            return;
+        }
+
+        // Oldest mtime first. `get` bumps an entry's mtime on every hit, so
+        // this is least-recently-*used* first, not just least-recently-written.
+        entries.sort_by_key(|(_, _, modified)| *modified);
+
+        for (path, size, _) in entries {
+            if total_size <= self.max_size_bytes {
+                break;
+            }
+            if fs::remove_file(&path).is_ok() {
+                total_size = total_size.saturating_sub(size);
+            }
+        }
+    }
+}
+
+// Bumps `path`'s mtime to now, so eviction's oldest-first ordering reflects
+// recency of use rather than only recency of creation. Best-effort: a
+// failure here shouldn't turn a cache hit into an error.
+fn touch(path: &Path) {
+    if let Ok(file) = fs::File::open(path) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
+
+// Hashes the image bytes together with every argument that can change
+// tesseract's output, so a cache hit is only ever returned for byte-identical
+// inputs run with byte-identical settings.
+fn digest(image_bytes: &[u8], args: &Args) -> String {
+    let mut hasher = blake3::Hasher::new();
+    // Variable-length fields are length-prefixed so that moving bytes from
+    // one field into the next can't produce the same digest.
+    hasher.update(&(image_bytes.len() as u64).to_le_bytes());
+    hasher.update(image_bytes);
+    hasher.update(&(args.lang.len() as u64).to_le_bytes());
+    hasher.update(args.lang.as_bytes());
+    hasher.update(&args.dpi.unwrap_or(0).to_le_bytes());
+    hasher.update(&args.psm.unwrap_or(-1).to_le_bytes());
+    hasher.update(&args.oem.unwrap_or(-1).to_le_bytes());
+
+    for parameter in args.get_config_variable_args() {
+        hasher.update(&(parameter.len() as u64).to_le_bytes());
+        hasher.update(parameter.as_bytes());
+    }
+
+    hasher.finalize().to_hex().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn test_digest_is_deterministic_for_identical_inputs() {
+        let args = Args::default();
+
+        assert_eq!(digest(b"hello", &args), digest(b"hello", &args));
+    }
+
+    #[test]
+    fn test_digest_differs_when_image_bytes_differ() {
+        let args = Args::default();
+
+        assert_ne!(digest(b"hello", &args), digest(b"world", &args));
+    }
+
+    #[test]
+    fn test_digest_differs_when_args_differ() {
+        let mut other_lang = Args::default();
+        other_lang.lang = "fra".into();
+
+        assert_ne!(
+            digest(b"hello", &Args::default()),
+            digest(b"hello", &other_lang)
+        );
+    }
+
+    #[test]
+    fn test_evict_keeps_most_recently_used_entries() {
+        let dir = std::env::temp_dir().join(format!(
+            "rusty-tesseract-cache-test-{}-{}",
+            std::process::id(),
+            line!()
+        ));
+        let cache = Cache::new(&dir, 20).unwrap();
+        let args = Args::default();
+
+        cache.put(b"a", &args, "aaaaaaaaaa").unwrap();
+        sleep(Duration::from_millis(10));
+        cache.put(b"b", &args, "bbbbbbbbbb").unwrap();
+        sleep(Duration::from_millis(10));
+
+        // "a" is now the least recently used entry; touch it so "b" becomes
+        // the eviction candidate instead.
+        cache.get(b"a", &args).unwrap();
+        sleep(Duration::from_millis(10));
+
+        cache.put(b"c", &args, "cccccccccc").unwrap();
+
+        assert!(cache.get(b"a", &args).unwrap().is_some());
+        assert!(cache.get(b"b", &args).unwrap().is_none());
+        assert!(cache.get(b"c", &args).unwrap().is_some());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,6 +1,11 @@
 use super::*;
-use std::process::{Command, Stdio};
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Command, Output, Stdio};
 use std::string::ToString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
 use crate::error::{TessError, TessResult};
 
@@ -104,21 +109,141 @@ pub fn find_tesseract_path() -> Option<PathBuf> {
     None // Return None if tesseract is not found
 }
 
-pub(crate) fn get_tesseract_command() -> Command {
-    let tesseract = find_tesseract_path().unwrap();
+/// Configuration controlling how the tesseract executable is located and
+/// which environment is passed to the child process.
+///
+/// By default the executable comes from the `TESSERACT_PATH` environment
+/// variable when it is set, and from `find_tesseract_path` otherwise; a
+/// `TESSDATA_PREFIX` set in the environment is passed on to the child
+/// unchanged. Use the builder methods
+/// to pin an explicit binary, add extra search directories, or point
+/// `TESSDATA_PREFIX` at a custom traineddata folder.
+#[derive(Debug, Clone, Default)]
+pub struct TesseractEnv {
+    executable_path: Option<PathBuf>,
+    extra_search_dirs: Vec<PathBuf>,
+    tessdata_prefix: Option<PathBuf>,
+}
+
+impl TesseractEnv {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use this exact path instead of searching for tesseract.
+    pub fn executable_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.executable_path = Some(path.into());
+        self
+    }
+
+    /// Add an extra directory to search for the tesseract executable in, on
+    /// top of `find_tesseract_path`'s usual locations.
+    pub fn search_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.extra_search_dirs.push(dir.into());
+        self
+    }
+
+    /// Set `TESSDATA_PREFIX` in the child process environment, so a custom
+    /// traineddata directory is used without relying on global state.
+    pub fn tessdata_prefix(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.tessdata_prefix = Some(dir.into());
+        self
+    }
+
+    fn resolve_executable_path(&self) -> TessResult<PathBuf> {
+        if let Some(path) = &self.executable_path {
+            return Ok(path.clone());
+        }
+
+        if let Ok(path) = std::env::var("TESSERACT_PATH") {
+            return Ok(PathBuf::from(path));
+        }
+
+        for dir in &self.extra_search_dirs {
+            let candidate = dir.join(EXECUTABLE_NAME);
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+        }
+
+        find_tesseract_path().ok_or(TessError::TesseractNotFoundError)
+    }
+
+    fn resolve_tessdata_prefix(&self) -> Option<PathBuf> {
+        self.tessdata_prefix
+            .clone()
+            .or_else(|| std::env::var("TESSDATA_PREFIX").ok().map(PathBuf::from))
+    }
+
+    /// Errors with `TessError::UnsupportedVersion` if the installed
+    /// tesseract is older than `min`, since flags like `--psm`/`--oem`
+    /// availability depends on version.
+    pub fn require_version(&self, min: &str) -> TessResult<()> {
+        let installed = get_tesseract_version_with_env(self)?;
+
+        if parse_version(&installed) < parse_version(min) {
+            return Err(TessError::UnsupportedVersion {
+                installed,
+                required: min.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// Parses a tesseract `--version` line such as "tesseract 5.3.0" (or a bare
+// "5.3.0") into a comparable (major, minor, patch) tuple, treating missing
+// components as 0 so "5.3" still compares correctly against "5.3.0".
+fn parse_version(version: &str) -> (u32, u32, u32) {
+    let version = version
+        .lines()
+        .next()
+        .unwrap_or(version)
+        .split_whitespace()
+        .last()
+        .unwrap_or("");
+
+    let mut parts = version.split('.').map(|p| p.parse().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+pub(crate) fn get_tesseract_command() -> TessResult<Command> {
+    get_tesseract_command_with_env(&TesseractEnv::default())
+}
+
+pub(crate) fn get_tesseract_command_with_env(env: &TesseractEnv) -> TessResult<Command> {
+    let tesseract = env.resolve_executable_path()?;
+    let mut command = Command::new(tesseract);
 
-    Command::new(tesseract)
+    if let Some(tessdata_prefix) = env.resolve_tessdata_prefix() {
+        command.env("TESSDATA_PREFIX", tessdata_prefix);
+    }
+
+    Ok(command)
 }
 
 pub fn get_tesseract_version() -> TessResult<String> {
-    let mut command = get_tesseract_command();
+    get_tesseract_version_with_env(&TesseractEnv::default())
+}
+
+pub fn get_tesseract_version_with_env(env: &TesseractEnv) -> TessResult<String> {
+    let mut command = get_tesseract_command_with_env(env)?;
     command.arg("--version");
 
     run_tesseract_command(&mut command)
 }
 
 pub fn get_tesseract_langs() -> TessResult<Vec<String>> {
-    let mut command = get_tesseract_command();
+    get_tesseract_langs_with_env(&TesseractEnv::default())
+}
+
+pub fn get_tesseract_langs_with_env(env: &TesseractEnv) -> TessResult<Vec<String>> {
+    let mut command = get_tesseract_command_with_env(env)?;
     command.arg("--list-langs");
 
     let output = run_tesseract_command(&mut command)?;
@@ -144,14 +269,174 @@ pub(crate) fn run_tesseract_command(command: &mut Command) -> TessResult<String>
         .wait_with_output()
         .map_err(|_| TessError::TesseractNotFoundError)?;
 
+    collect_output(output)
+}
+
+// Like `run_tesseract_command`, but writes `image_bytes` to the child's stdin
+// before waiting on it, for commands built with `-` as their input filename.
+pub(crate) fn run_tesseract_command_with_stdin(
+    command: &mut Command,
+    image_bytes: &[u8],
+) -> TessResult<String> {
+    if cfg!(debug_assertions) {
+        show_command(command);
+    }
+
+    #[cfg(target_os = "windows")]
+    command.creation_flags(CREATE_NO_WINDOW);
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| TessError::TesseractNotFoundError)?;
+
+    {
+        let mut stdin = child
+            .stdin
+            .take()
+            .expect("stdin was requested with Stdio::piped");
+        // A write failure here (almost always a broken pipe) means tesseract
+        // already exited before reading all of stdin, not that the binary is
+        // missing — spawn() already succeeded. Ignore it and let
+        // `wait_with_output`/`classify_stderr` below surface the real cause.
+        let _ = stdin.write_all(image_bytes);
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|_| TessError::TesseractNotFoundError)?;
+
+    collect_output(output)
+}
+
+// Like `run_tesseract_command`, but streams recognized lines to `on_line` as
+// they're produced instead of buffering the whole transcript, so a multi-page
+// or high-resolution input doesn't have to sit fully in memory before a
+// caller sees anything. stderr is drained on a separate thread so a large
+// stdout can't block on a full stderr pipe (or vice versa).
+pub(crate) fn run_tesseract_command_streaming(
+    command: &mut Command,
+    on_line: &mut dyn FnMut(&str),
+) -> TessResult<()> {
+    if cfg!(debug_assertions) {
+        show_command(command);
+    }
+
+    #[cfg(target_os = "windows")]
+    command.creation_flags(CREATE_NO_WINDOW);
+
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| TessError::TesseractNotFoundError)?;
+
+    let stderr = child
+        .stderr
+        .take()
+        .expect("stderr was requested with Stdio::piped");
+    let stderr_reader = thread::spawn(move || {
+        let mut buf = String::new();
+        BufReader::new(stderr).read_to_string(&mut buf).ok();
+        buf
+    });
+
+    let stdout = child
+        .stdout
+        .take()
+        .expect("stdout was requested with Stdio::piped");
+    for line in BufReader::new(stdout).lines() {
+        let line = line.map_err(|_| TessError::TesseractNotFoundError)?;
+        on_line(&line);
+    }
+
+    let status = child
+        .wait()
+        .map_err(|_| TessError::TesseractNotFoundError)?;
+    let err = stderr_reader.join().unwrap_or_default();
+
+    match status.code() {
+        Some(0) => Ok(()),
+        _ => Err(classify_stderr(&err, status.to_string())),
+    }
+}
+
+fn collect_output(output: Output) -> TessResult<String> {
     let out = String::from_utf8(output.stdout).unwrap();
     let err = String::from_utf8(output.stderr).unwrap();
     let status = output.status;
 
     match status.code() {
         Some(0) => Ok(out),
-        _ => Err(TessError::CommandExitStatusError(status.to_string(), err)),
+        _ => Err(classify_stderr(&err, status.to_string())),
+    }
+}
+
+// Classifies a non-zero-exit stderr into one of the known tesseract failure
+// modes, so callers can match on e.g. `LanguageDataMissing` instead of
+// string-grepping. Falls back to `CommandExitStatusError` when nothing
+// recognized is found, keeping the raw stderr either way.
+fn classify_stderr(stderr: &str, status: String) -> TessError {
+    if let Some((lang, tessdata_dir)) = parse_missing_language_data(stderr) {
+        return TessError::LanguageDataMissing {
+            lang,
+            tessdata_dir,
+            stderr: stderr.to_string(),
+        };
+    }
+
+    if stderr.contains("Invalid page segmentation mode") {
+        return TessError::InvalidPageSegMode {
+            stderr: stderr.to_string(),
+        };
     }
+
+    if stderr.contains("Invalid oem value") || stderr.contains("OCR Engine mode") {
+        return TessError::InvalidOcrEngineMode {
+            stderr: stderr.to_string(),
+        };
+    }
+
+    if stderr.contains("Image too large") {
+        return TessError::ImageTooLarge {
+            stderr: stderr.to_string(),
+        };
+    }
+
+    if stderr.contains("Empty page") {
+        return TessError::EmptyPage {
+            stderr: stderr.to_string(),
+        };
+    }
+
+    // Checked last: tesseract emits this on essentially any image without
+    // embedded DPI metadata (the norm for in-memory frames/screenshots), so
+    // it must not shadow a more specific, usually-fatal cause appearing
+    // elsewhere in the same stderr blob.
+    if stderr.contains("Invalid resolution") || stderr.contains("dpi") {
+        return TessError::DpiWarning {
+            stderr: stderr.to_string(),
+        };
+    }
+
+    TessError::CommandExitStatusError(status, stderr.to_string())
+}
+
+// Parses the `lang` and `tessdata_dir` out of tesseract's
+// "Error opening data file /path/to/tessdata/lang.traineddata" message.
+fn parse_missing_language_data(stderr: &str) -> Option<(String, String)> {
+    const MARKER: &str = "Error opening data file ";
+    let start = stderr.find(MARKER)? + MARKER.len();
+    let rest = &stderr[start..];
+    let end = rest.find(['\n', '\r']).unwrap_or(rest.len());
+    let path = PathBuf::from(rest[..end].trim().trim_end_matches(".traineddata"));
+
+    let lang = path.file_name()?.to_str()?.to_string();
+    let tessdata_dir = path.parent()?.to_str()?.to_string();
+
+    Some((lang, tessdata_dir))
 }
 
 fn show_command(command: &Command) {
@@ -169,16 +454,189 @@ fn show_command(command: &Command) {
 }
 
 pub fn image_to_string(image: &Image, args: &Args) -> TessResult<String> {
-    let mut command = create_tesseract_command(image, args)?;
+    image_to_string_with_env(image, args, &TesseractEnv::default())
+}
+
+// Like `image_to_string`, but resolves the tesseract binary and child
+// environment through `env` instead of the default search path, so a pinned
+// executable or custom `TESSDATA_PREFIX` is actually used to run OCR, not
+// just to probe the version/langs.
+pub fn image_to_string_with_env(
+    image: &Image,
+    args: &Args,
+    env: &TesseractEnv,
+) -> TessResult<String> {
+    let mut command = create_tesseract_command_with_env(image, args, env)?;
     let output = run_tesseract_command(&mut command)?;
 
     Ok(output)
 }
 
+// Runs tesseract over already-decoded image bytes (e.g. a video frame or a
+// screenshot buffer) by piping them through stdin instead of round-tripping
+// through a temp file on disk.
+pub fn image_to_string_from_bytes(image_bytes: &[u8], args: &Args) -> TessResult<String> {
+    image_to_string_from_bytes_with_env(image_bytes, args, &TesseractEnv::default())
+}
+
+// Like `image_to_string_from_bytes`, but resolves the tesseract binary and
+// child environment through `env`.
+pub fn image_to_string_from_bytes_with_env(
+    image_bytes: &[u8],
+    args: &Args,
+    env: &TesseractEnv,
+) -> TessResult<String> {
+    let mut command = create_tesseract_command_from_bytes_with_env(args, env)?;
+    let output = run_tesseract_command_with_stdin(&mut command, image_bytes)?;
+
+    Ok(output)
+}
+
+// Like `image_to_string_from_bytes`, but checks `cache` for a result keyed on
+// `image_bytes` and `args` before spawning tesseract, and persists the
+// output back to the cache on a miss.
+pub fn image_to_string_from_bytes_with_cache(
+    image_bytes: &[u8],
+    args: &Args,
+    cache: &Cache,
+) -> TessResult<String> {
+    if let Some(cached) = cache.get(image_bytes, args)? {
+        return Ok(cached);
+    }
+
+    let output = image_to_string_from_bytes(image_bytes, args)?;
+    cache.put(image_bytes, args, &output)?;
+
+    Ok(output)
+}
+
+// Like `image_to_string`, but checks `cache` for a result keyed on the
+// image's on-disk bytes and `args` before spawning tesseract, persisting the
+// output back to the cache on a miss. This is the common path for repeated
+// OCR over the same document set.
+pub fn image_to_string_with_cache(image: &Image, args: &Args, cache: &Cache) -> TessResult<String> {
+    let image_bytes =
+        fs::read(image.get_image_path()?).map_err(|e| TessError::CacheIoError(e.to_string()))?;
+
+    if let Some(cached) = cache.get(&image_bytes, args)? {
+        return Ok(cached);
+    }
+
+    let output = image_to_string(image, args)?;
+    cache.put(&image_bytes, args, &output)?;
+
+    Ok(output)
+}
+
+// Runs tesseract over `images` with at most `concurrency` processes in
+// flight at once, returning one result per image in input order.
+//
+// `concurrency` persistent worker threads pull the next image off a shared
+// counter as soon as they finish their previous one, rather than waiting on
+// fixed-size chunks — so one slow, high-resolution image doesn't stall
+// already-finished slots until the rest of its chunk catches up.
+pub fn image_to_string_batch(
+    images: &[Image],
+    args: &Args,
+    concurrency: usize,
+) -> Vec<TessResult<String>> {
+    image_to_string_batch_with_env(images, args, concurrency, &TesseractEnv::default())
+}
+
+// Like `image_to_string_batch`, but resolves the tesseract binary and child
+// environment through `env`.
+pub fn image_to_string_batch_with_env(
+    images: &[Image],
+    args: &Args,
+    concurrency: usize,
+    env: &TesseractEnv,
+) -> Vec<TessResult<String>> {
+    let concurrency = concurrency.max(1).min(images.len().max(1));
+    let next_index = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<TessResult<String>>>> =
+        Mutex::new((0..images.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..concurrency {
+            scope.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::SeqCst);
+                if index >= images.len() {
+                    break;
+                }
+
+                let result = (|| {
+                    let mut command = create_tesseract_command_with_env(&images[index], args, env)?;
+                    run_tesseract_command(&mut command)
+                })();
+
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every index in 0..images.len() is filled exactly once"))
+        .collect()
+}
+
+// Like `image_to_string`, but calls `on_line` with each recognized line as
+// soon as tesseract emits it, instead of returning the whole transcript at
+// once.
+pub fn image_to_string_streaming(
+    image: &Image,
+    args: &Args,
+    on_line: impl FnMut(&str),
+) -> TessResult<()> {
+    image_to_string_streaming_with_env(image, args, on_line, &TesseractEnv::default())
+}
+
+// Like `image_to_string_streaming`, but resolves the tesseract binary and
+// child environment through `env`.
+pub fn image_to_string_streaming_with_env(
+    image: &Image,
+    args: &Args,
+    mut on_line: impl FnMut(&str),
+    env: &TesseractEnv,
+) -> TessResult<()> {
+    let mut command = create_tesseract_command_with_env(image, args, env)?;
+    run_tesseract_command_streaming(&mut command, &mut on_line)
+}
+
 pub(crate) fn create_tesseract_command(image: &Image, args: &Args) -> TessResult<Command> {
-    let mut command = get_tesseract_command();
+    create_tesseract_command_with_env(image, args, &TesseractEnv::default())
+}
+
+pub(crate) fn create_tesseract_command_with_env(
+    image: &Image,
+    args: &Args,
+    env: &TesseractEnv,
+) -> TessResult<Command> {
+    create_tesseract_command_with_input(image.get_image_path()?, args, env)
+}
+
+// `-` tells tesseract to read the input image from stdin rather than from a file.
+pub(crate) fn create_tesseract_command_from_bytes(args: &Args) -> TessResult<Command> {
+    create_tesseract_command_from_bytes_with_env(args, &TesseractEnv::default())
+}
+
+pub(crate) fn create_tesseract_command_from_bytes_with_env(
+    args: &Args,
+    env: &TesseractEnv,
+) -> TessResult<Command> {
+    create_tesseract_command_with_input("-", args, env)
+}
+
+fn create_tesseract_command_with_input(
+    input: impl AsRef<std::ffi::OsStr>,
+    args: &Args,
+    env: &TesseractEnv,
+) -> TessResult<Command> {
+    let mut command = get_tesseract_command_with_env(env)?;
     command
-        .arg(image.get_image_path()?)
+        .arg(input)
         .arg("stdout")
         .arg("-l")
         .arg(args.lang.clone());
@@ -204,7 +662,7 @@ pub(crate) fn create_tesseract_command(image: &Image, args: &Args) -> TessResult
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use super::*;
 
     #[test]
     fn test_get_tesseract_langs() {
@@ -212,4 +670,174 @@ mod tests {
 
         assert!(langs.contains(&"eng".into()));
     }
+
+    #[test]
+    fn test_parse_version_treats_missing_components_as_zero() {
+        assert_eq!(parse_version("5.3"), parse_version("5.3.0"));
+        assert_eq!(parse_version("tesseract 5.3.0"), (5, 3, 0));
+        assert!(parse_version("4.1.1") < parse_version("5.0.0"));
+    }
+
+    #[test]
+    fn test_parse_missing_language_data_extracts_lang_and_dir() {
+        let stderr = "Error opening data file /usr/share/tesseract-ocr/4.00/tessdata/xyz.traineddata\nPlease make sure the TESSDATA_PREFIX environment variable is set";
+
+        let (lang, tessdata_dir) = parse_missing_language_data(stderr).unwrap();
+
+        assert_eq!(lang, "xyz");
+        assert_eq!(tessdata_dir, "/usr/share/tesseract-ocr/4.00/tessdata");
+    }
+
+    #[test]
+    fn test_parse_missing_language_data_returns_none_for_unrelated_stderr() {
+        assert!(parse_missing_language_data("some other failure").is_none());
+    }
+
+    #[test]
+    fn test_classify_stderr_recognizes_known_failure_modes() {
+        assert!(matches!(
+            classify_stderr(
+                "Error opening data file /tessdata/xyz.traineddata",
+                "1".into()
+            ),
+            TessError::LanguageDataMissing { .. }
+        ));
+        assert!(matches!(
+            classify_stderr("Invalid page segmentation mode", "1".into()),
+            TessError::InvalidPageSegMode { .. }
+        ));
+        assert!(matches!(
+            classify_stderr("Invalid oem value", "1".into()),
+            TessError::InvalidOcrEngineMode { .. }
+        ));
+        assert!(matches!(
+            classify_stderr(
+                "Warning: Invalid resolution 0 dpi. Using 70 instead.",
+                "1".into()
+            ),
+            TessError::DpiWarning { .. }
+        ));
+        assert!(matches!(
+            classify_stderr("Image too large", "1".into()),
+            TessError::ImageTooLarge { .. }
+        ));
+        assert!(matches!(
+            classify_stderr("Empty page!!", "1".into()),
+            TessError::EmptyPage { .. }
+        ));
+    }
+
+    #[test]
+    fn test_classify_stderr_falls_back_to_command_exit_status_error() {
+        assert!(matches!(
+            classify_stderr("something unexpected", "1".into()),
+            TessError::CommandExitStatusError(..)
+        ));
+    }
+
+    #[test]
+    fn test_classify_stderr_prefers_fatal_cause_over_dpi_warning() {
+        let stderr = "Warning: Invalid resolution 0 dpi. Using 70 instead.\nImage too large";
+
+        assert!(matches!(
+            classify_stderr(stderr, "1".into()),
+            TessError::ImageTooLarge { .. }
+        ));
+    }
+
+    // `TESSERACT_PATH`/`TESSDATA_PREFIX` are process-global, so the tests
+    // below that touch them take this lock for their whole duration to avoid
+    // racing against each other under the default parallel test runner.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_resolve_executable_path_prefers_explicit_value_over_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("TESSERACT_PATH", "/env/tesseract");
+
+        let env = TesseractEnv::new().executable_path("/explicit/tesseract");
+
+        assert_eq!(
+            env.resolve_executable_path().unwrap(),
+            PathBuf::from("/explicit/tesseract")
+        );
+
+        std::env::remove_var("TESSERACT_PATH");
+    }
+
+    #[test]
+    fn test_resolve_executable_path_falls_back_to_env_var() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("TESSERACT_PATH", "/env/tesseract");
+
+        let env = TesseractEnv::new();
+
+        assert_eq!(
+            env.resolve_executable_path().unwrap(),
+            PathBuf::from("/env/tesseract")
+        );
+
+        std::env::remove_var("TESSERACT_PATH");
+    }
+
+    #[test]
+    fn test_resolve_executable_path_falls_back_to_search_dir() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("TESSERACT_PATH");
+
+        let dir = std::env::temp_dir().join(format!(
+            "rusty-tesseract-search-dir-test-{}-{}",
+            std::process::id(),
+            line!()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(EXECUTABLE_NAME), []).unwrap();
+
+        let env = TesseractEnv::new().search_dir(&dir);
+
+        assert_eq!(
+            env.resolve_executable_path().unwrap(),
+            dir.join(EXECUTABLE_NAME)
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_resolve_tessdata_prefix_prefers_explicit_value_over_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("TESSDATA_PREFIX", "/env/tessdata");
+
+        let env = TesseractEnv::new().tessdata_prefix("/explicit/tessdata");
+
+        assert_eq!(
+            env.resolve_tessdata_prefix(),
+            Some(PathBuf::from("/explicit/tessdata"))
+        );
+
+        std::env::remove_var("TESSDATA_PREFIX");
+    }
+
+    #[test]
+    fn test_resolve_tessdata_prefix_falls_back_to_env_var() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("TESSDATA_PREFIX", "/env/tessdata");
+
+        let env = TesseractEnv::new();
+
+        assert_eq!(
+            env.resolve_tessdata_prefix(),
+            Some(PathBuf::from("/env/tessdata"))
+        );
+
+        std::env::remove_var("TESSDATA_PREFIX");
+    }
+
+    #[test]
+    fn test_resolve_tessdata_prefix_is_none_without_explicit_value_or_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::remove_var("TESSDATA_PREFIX");
+
+        assert_eq!(TesseractEnv::new().resolve_tessdata_prefix(), None);
+    }
 }